@@ -5,6 +5,8 @@ use crate::{
     SyntaxKind::{self, *},
     SyntaxToken, TextRange, TextUnit,
 };
+use smol_str::SmolStr;
+use std::num::IntErrorKind;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum PrefixOp {
@@ -34,24 +36,137 @@ pub enum BinOp {
     Subtract,
     Divide,
     Multiply,
-    //    Remainder,
-    //    Power,
+    /// The `%` operator.
+    ///
+    /// Only the syntax-level variant and its `op_details`/`PERCENT` wiring
+    /// land here. Lexing, operand type checking (integers/floats), and the
+    /// LLVM `rem` codegen live in other crates not present in this tree and
+    /// are not implemented by this change.
+    Remainder,
+    /// The power operator, written `^` (the `CARET` token), right-associative
+    /// and binding tighter than unary `-`.
+    ///
+    /// Same caveat as [`BinOp::Remainder`]: syntax-level only. Lowering it
+    /// to repeated multiplication or an `llvm.powi`/`llvm.pow` call is not
+    /// implemented here.
+    Power,
     Assign,
     AddAssign,
     SubtractAssign,
     DivideAssign,
     MultiplyAssign,
-    //    RemainderAssign,
-    //    PowerAssign,
+    /// See [`BinOp::Remainder`] — syntax-level only.
+    RemainderAssign,
+    /// See [`BinOp::Power`] — syntax-level only.
+    PowerAssign,
     Equals,
     NotEquals,
     LessEqual,
     Less,
     GreatEqual,
     Greater,
+    /// The `&&` operator for short-circuiting logical conjunction.
+    ///
+    /// Only the `BinOp` variant itself lands in this commit. Unlike
+    /// `Remainder`/`Power`, there is no `SyntaxKind::AMPAMP` token anywhere in
+    /// the tree yet, so `op_details` cannot be wired up to recognize `&&`
+    /// without that token kind existing first — doing so would reference an
+    /// undefined identifier and fail to compile. The `op_details` arm is left
+    /// commented out (see below) until `AMPAMP`/`PIPEPIPE` land, alongside
+    /// the lexer, parser precedence, HIR lowering, bool-only type checking,
+    /// and short-circuiting codegen this operator still needs.
+    BooleanAnd,
+    /// The `||` operator for short-circuiting logical disjunction.
+    ///
+    /// Same caveat as [`BinOp::BooleanAnd`]: no `SyntaxKind::PIPEPIPE` token
+    /// exists yet, so `op_details` can't be wired up for this either.
+    BooleanOr,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+impl BinOp {
+    pub fn is_assignment(self) -> bool {
+        matches!(
+            self,
+            BinOp::Assign
+                | BinOp::AddAssign
+                | BinOp::SubtractAssign
+                | BinOp::DivideAssign
+                | BinOp::MultiplyAssign
+                | BinOp::RemainderAssign
+                | BinOp::PowerAssign
+        )
+    }
+
+    pub fn is_comparison(self) -> bool {
+        matches!(
+            self,
+            BinOp::Equals
+                | BinOp::NotEquals
+                | BinOp::LessEqual
+                | BinOp::Less
+                | BinOp::GreatEqual
+                | BinOp::Greater
+        )
+    }
+
+    pub fn is_arithmetic(self) -> bool {
+        matches!(
+            self,
+            BinOp::Add
+                | BinOp::Subtract
+                | BinOp::Divide
+                | BinOp::Multiply
+                | BinOp::Remainder
+                | BinOp::Power
+        )
+    }
+
+    /// Returns the binding strength of this operator, with higher values
+    /// binding tighter: assignments (1), `||` (2), `&&` (3), comparisons (4),
+    /// `+`/`-` (5), `*`/`/`/`%` (6) and `^` (7).
+    pub fn precedence(self) -> u8 {
+        match self {
+            BinOp::Assign
+            | BinOp::AddAssign
+            | BinOp::SubtractAssign
+            | BinOp::DivideAssign
+            | BinOp::MultiplyAssign
+            | BinOp::RemainderAssign
+            | BinOp::PowerAssign => 1,
+            BinOp::BooleanOr => 2,
+            BinOp::BooleanAnd => 3,
+            BinOp::Equals
+            | BinOp::NotEquals
+            | BinOp::LessEqual
+            | BinOp::Less
+            | BinOp::GreatEqual
+            | BinOp::Greater => 4,
+            BinOp::Add | BinOp::Subtract => 5,
+            BinOp::Divide | BinOp::Multiply | BinOp::Remainder => 6,
+            BinOp::Power => 7,
+        }
+    }
+
+    pub fn associativity(self) -> Associativity {
+        match self {
+            BinOp::Power => Associativity::Right,
+            op if op.is_assignment() => Associativity::Right,
+            _ => Associativity::Left,
+        }
+    }
 }
 
 impl BinExpr {
+    pub fn is_assignment(&self) -> bool {
+        self.op_kind().map_or(false, BinOp::is_assignment)
+    }
+
     pub fn op_details(&self) -> Option<(SyntaxToken, BinOp)> {
         use SyntaxKind::*;
         self.syntax()
@@ -62,21 +177,23 @@ impl BinExpr {
                 MINUS => Some((c, BinOp::Subtract)),
                 SLASH => Some((c, BinOp::Divide)),
                 STAR => Some((c, BinOp::Multiply)),
-                //                PERCENT => Some((c, BinOp::Remainder)),
-                //                CARET => Some((c, BinOp::Power)),
+                PERCENT => Some((c, BinOp::Remainder)),
+                CARET => Some((c, BinOp::Power)),
                 T![=] => Some((c, BinOp::Assign)),
                 PLUSEQ => Some((c, BinOp::AddAssign)),
                 MINUSEQ => Some((c, BinOp::SubtractAssign)),
                 SLASHEQ => Some((c, BinOp::DivideAssign)),
                 STAREQ => Some((c, BinOp::MultiplyAssign)),
-                //                PERCENTEQ => Some((c, BinOp::RemainderAssign)),
-                //                CARETEQ => Some((c, BinOp::PowerAssign)),
+                PERCENTEQ => Some((c, BinOp::RemainderAssign)),
+                CARETEQ => Some((c, BinOp::PowerAssign)),
                 EQEQ => Some((c, BinOp::Equals)),
                 NEQ => Some((c, BinOp::NotEquals)),
                 LT => Some((c, BinOp::Less)),
                 LTEQ => Some((c, BinOp::LessEqual)),
                 GT => Some((c, BinOp::Greater)),
                 GTEQ => Some((c, BinOp::GreatEqual)),
+                //                AMPAMP => Some((c, BinOp::BooleanAnd)),
+                //                PIPEPIPE => Some((c, BinOp::BooleanOr)),
                 _ => None,
             })
     }
@@ -174,6 +291,227 @@ impl Literal {
             _ => unreachable!(),
         }
     }
+
+    /// Returns the decoded value of this literal.
+    pub fn value(&self) -> Result<LiteralValue, LiteralError> {
+        let token = self.token();
+        let text = token.text().as_str();
+        match self.kind() {
+            LiteralKind::Bool => Ok(LiteralValue::Bool(text == "true")),
+            LiteralKind::IntNumber => Ok(LiteralValue::Int(parse_int_literal(text)?)),
+            LiteralKind::FloatNumber => Ok(LiteralValue::Float(parse_float_literal(text)?)),
+            LiteralKind::String => {
+                let contents = text.strip_prefix('"').unwrap_or(text);
+                let contents = contents.strip_suffix('"').unwrap_or(contents);
+                Ok(LiteralValue::String(unescape_string(contents)?))
+            }
+        }
+    }
+
+    /// Returns the type suffix of this literal, e.g. `u8` in `255u8`, if any.
+    pub fn suffix(&self) -> Option<SmolStr> {
+        let token = self.token();
+        let text = token.text().as_str();
+        match self.kind() {
+            LiteralKind::IntNumber => split_suffix(text, INT_SUFFIXES).1,
+            LiteralKind::FloatNumber => split_suffix(text, FLOAT_SUFFIXES).1,
+            LiteralKind::String | LiteralKind::Bool => None,
+        }
+        .map(SmolStr::from)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiteralValue {
+    Int(u128),
+    Float(f64),
+    String(String),
+    Bool(bool),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiteralError {
+    /// The integer literal does not fit in a `u128`
+    IntOverflow,
+    /// A digit in the integer literal is not valid for its radix (e.g. `0b2`,
+    /// `0o8`), or the literal has no digits at all
+    InvalidDigit,
+    /// The float literal could not be parsed
+    InvalidFloat,
+    /// A `\` escape sequence in a string literal was malformed
+    MalformedEscape,
+}
+
+const INT_SUFFIXES: &[&str] = &[
+    "i128", "u128", "i64", "u64", "i32", "u32", "i16", "u16", "i8", "u8",
+];
+const FLOAT_SUFFIXES: &[&str] = &["f32", "f64"];
+
+/// Splits the longest matching type suffix off the end of `text`, returning
+/// the remaining digits and the suffix, if any.
+fn split_suffix<'a>(text: &'a str, suffixes: &[&'static str]) -> (&'a str, Option<&'static str>) {
+    for &suffix in suffixes {
+        if text.ends_with(suffix) && text.len() > suffix.len() {
+            return (&text[..text.len() - suffix.len()], Some(suffix));
+        }
+    }
+    (text, None)
+}
+
+/// Splits a leading `0x`/`0o`/`0b` base prefix off `text`, returning the
+/// remaining digits and the radix to parse them with.
+fn split_radix(text: &str) -> (&str, u32) {
+    if let Some(rest) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        (rest, 16)
+    } else if let Some(rest) = text.strip_prefix("0o").or_else(|| text.strip_prefix("0O")) {
+        (rest, 8)
+    } else if let Some(rest) = text.strip_prefix("0b").or_else(|| text.strip_prefix("0B")) {
+        (rest, 2)
+    } else {
+        (text, 10)
+    }
+}
+
+/// Parses the full text of an `INT_NUMBER` token (including any base prefix,
+/// digit separators, and type suffix) into its `u128` value.
+fn parse_int_literal(text: &str) -> Result<u128, LiteralError> {
+    let (digits, _suffix) = split_suffix(text, INT_SUFFIXES);
+    let (digits, radix) = split_radix(digits);
+    let digits: String = digits.chars().filter(|&c| c != '_').collect();
+    u128::from_str_radix(&digits, radix).map_err(|e| match e.kind() {
+        IntErrorKind::PosOverflow => LiteralError::IntOverflow,
+        _ => LiteralError::InvalidDigit,
+    })
+}
+
+/// Parses the full text of a `FLOAT_NUMBER` token (including digit
+/// separators and type suffix) into its `f64` value.
+fn parse_float_literal(text: &str) -> Result<f64, LiteralError> {
+    let (digits, _suffix) = split_suffix(text, FLOAT_SUFFIXES);
+    let digits: String = digits.chars().filter(|&c| c != '_').collect();
+    digits.parse::<f64>().map_err(|_| LiteralError::InvalidFloat)
+}
+
+/// Decodes `\n`, `\t`, `\\`, `\"`, and `\u{...}` escape sequences in the
+/// contents of a string literal (without the surrounding quotes).
+fn unescape_string(contents: &str) -> Result<String, LiteralError> {
+    let mut result = String::with_capacity(contents.len());
+    let mut chars = contents.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next().ok_or(LiteralError::MalformedEscape)? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            '\\' => result.push('\\'),
+            '"' => result.push('"'),
+            'u' => {
+                if chars.next() != Some('{') {
+                    return Err(LiteralError::MalformedEscape);
+                }
+                let mut code = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => code.push(c),
+                        None => return Err(LiteralError::MalformedEscape),
+                    }
+                }
+                let code_point =
+                    u32::from_str_radix(&code, 16).map_err(|_| LiteralError::MalformedEscape)?;
+                let c = char::from_u32(code_point).ok_or(LiteralError::MalformedEscape)?;
+                result.push(c);
+            }
+            _ => return Err(LiteralError::MalformedEscape),
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    // `Literal` values can only be obtained from a parsed syntax tree, and
+    // the lexer/parser are not part of this chunk of the tree, so these
+    // tests exercise the decoding helpers directly rather than going through
+    // `Literal::value()`/`Literal::suffix()`.
+    use super::*;
+
+    #[test]
+    fn suffix_splitting_picks_longest_match() {
+        assert_eq!(split_suffix("255u8", INT_SUFFIXES), ("255", Some("u8")));
+        assert_eq!(split_suffix("255u128", INT_SUFFIXES), ("255", Some("u128")));
+        assert_eq!(split_suffix("255", INT_SUFFIXES), ("255", None));
+        assert_eq!(split_suffix("1.0f32", FLOAT_SUFFIXES), ("1.0", Some("f32")));
+    }
+
+    #[test]
+    fn radix_prefixes() {
+        assert_eq!(split_radix("0xff"), ("ff", 16));
+        assert_eq!(split_radix("0o17"), ("17", 8));
+        assert_eq!(split_radix("0b101"), ("101", 2));
+        assert_eq!(split_radix("123"), ("123", 10));
+    }
+
+    #[test]
+    fn int_literal_with_suffix_and_separators() {
+        assert_eq!(parse_int_literal("255u8"), Ok(255));
+        assert_eq!(parse_int_literal("1_000_000"), Ok(1_000_000));
+        assert_eq!(parse_int_literal("0xFF_u32"), Ok(255));
+        assert_eq!(parse_int_literal("0b1010"), Ok(10));
+    }
+
+    #[test]
+    fn int_literal_invalid_digit_is_not_reported_as_overflow() {
+        assert_eq!(parse_int_literal("0b2"), Err(LiteralError::InvalidDigit));
+        assert_eq!(parse_int_literal("0o8"), Err(LiteralError::InvalidDigit));
+    }
+
+    #[test]
+    fn int_literal_overflow() {
+        assert_eq!(
+            parse_int_literal("340282366920938463463374607431768211456"), // u128::MAX + 1
+            Err(LiteralError::IntOverflow)
+        );
+    }
+
+    #[test]
+    fn float_literal_with_separators_and_exponent() {
+        assert_eq!(parse_float_literal("1_000.5"), Ok(1_000.5));
+        assert_eq!(parse_float_literal("1.5e10f64"), Ok(1.5e10));
+    }
+
+    #[test]
+    fn string_escape_decoding() {
+        assert_eq!(unescape_string(r#"a\nb\tc\\d\"e"#), Ok("a\nb\tc\\d\"e".to_string()));
+        assert_eq!(unescape_string(r#"\u{1F600}"#), Ok("\u{1F600}".to_string()));
+    }
+
+    #[test]
+    fn string_malformed_escape() {
+        assert_eq!(unescape_string(r"\q"), Err(LiteralError::MalformedEscape));
+        assert_eq!(unescape_string(r"\u{zzzz}"), Err(LiteralError::MalformedEscape));
+        assert_eq!(unescape_string(r"\u{110000}"), Err(LiteralError::MalformedEscape));
+        assert_eq!(unescape_string("\\"), Err(LiteralError::MalformedEscape));
+    }
+}
+
+impl ast::Expr {
+    /// Returns `true` if this expression is block-like, i.e. it ends in a
+    /// `}` and therefore doesn't need a trailing `;` when used as the last
+    /// expression of a statement.
+    ///
+    /// `IfExpr` and `BlockExpr` are confirmed `ast::Expr` variants (see
+    /// `ElseBranch`/`IfExpr::then_branch` below). Mun also has loop/while
+    /// constructs that should be block-like, but this file never references
+    /// their `ast::Expr` variant names, so guessing at `LoopExpr`/`WhileExpr`
+    /// here risked silently matching nothing if the real names differ
+    /// (`matches!` doesn't require exhaustiveness). Add them once the actual
+    /// variant names are confirmed against `ast::Expr`'s definition.
+    pub fn is_block_like(&self) -> bool {
+        matches!(self, ast::Expr::IfExpr(_) | ast::Expr::BlockExpr(_))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]